@@ -0,0 +1,31 @@
+//! Unstyled, accessible UI primitives for Dioxus, following the
+//! [ARIA APG](https://www.w3.org/WAI/ARIA/apg/) patterns.
+
+pub mod switch;
+pub mod toggle;
+
+use dioxus_lib::prelude::*;
+
+/// Mirrors a value that may be controlled (`controlled_value` is `Some`) or
+/// uncontrolled (only `default_value` is used), always routing writes through
+/// `on_change` so controlled and uncontrolled consumers see every update.
+pub(crate) fn use_controlled<T: Clone + PartialEq + 'static>(
+    controlled_value: Option<Signal<T>>,
+    default_value: T,
+    on_change: Callback<T>,
+) -> (Signal<T>, Callback<T>) {
+    let mut internal_value = use_signal(|| default_value);
+
+    use_effect(move || {
+        if let Some(controlled_value) = controlled_value {
+            internal_value.set(controlled_value());
+        }
+    });
+
+    let set_value = use_callback(move |new_value: T| {
+        internal_value.set(new_value.clone());
+        on_change.call(new_value);
+    });
+
+    (internal_value, set_value)
+}
@@ -7,8 +7,8 @@
 //! ## Example
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_primitives::switch::{Switch, SwitchThumb};
-//! 
+//! use dioxus_primitives::switch::{Switch, SwitchLabel, SwitchThumb};
+//!
 //! #[component]
 //! fn MyComponent() -> Element {
 //!     rsx! {
@@ -16,7 +16,8 @@
 //!             class: "switch",
 //!             default_checked: false,
 //!             on_checked_change: move |new_checked| println!("checked: {new_checked}"),
-//!             
+//!
+//!             SwitchLabel { on_label: "On", off_label: "Off" }
 //!             SwitchThumb { class: "switch-thumb" }
 //!         }
 //!     }
@@ -30,9 +31,12 @@
 //! | `checked`             | The controlled checked value.                             | `None`    |
 //! | `default_checked`     | The default checked state.                                | `false`   |
 //! | `disabled`            | Whether the switch is disabled.                           | `false`   |
+//! | `read_only`           | Whether the switch is read-only.                          | `false`   |
 //! | `required`            | Whether the switch is required in a form.                 | `false`   |
 //! | `name`                | The form name of the switch.                              | `None`    |
+//! | `invalid`             | Whether the switch currently fails validation.            | `false`   |
 //! | `on_checked_change`   | Callback for state changes. Required with `checked` prop. | `None`    |
+//! | `on_validity_change`  | `Callback<bool>`, called with the new native validity (`true` = passes constraint validation, `false` = invalid) whenever it changes. | `None` |
 //! 
 //! ### Attributes
 //! 
@@ -40,17 +44,72 @@
 //! | ----------------- | ------------------------- |
 //! | `data-state`      | `checked` or `unchecked`  |
 //! | `data-disabled`   | `true` or `false`         |
+//! | `data-readonly`   | `true` or `false`         |
+//! | `data-invalid`    | `true` or `false`         |
 //! 
 //! ## Accessibility
 //! 
 //! Follows the ARIA `switch` [role requirements](https://www.w3.org/WAI/ARIA/apg/patterns/switch/).
 //! 
 //! **Keyboard Interactions**
-//! | Key   | Description           |
-//! | ----- | --------------------- |
-//! | Space | Toggle the switch.    |
+//! | Key   | Description                                                  |
+//! | ----- | ------------------------------------------------------------- |
+//! | Space | Toggle the switch (on key up, matching native button semantics). |
+//! | Enter | Submit the enclosing form, if any.                           |
+//!
+//! ## Labelling
+//!
+//! [`SwitchLabel`] can be rendered inside [`Switch`] to give it an accessible
+//! name. The two wire themselves together automatically: the label generates a
+//! stable id, the switch points `aria-labelledby` at it, and the label's text
+//! swaps between `on_label`/`off_label` based on the switch's `data-state`.
 use crate::use_controlled;
 use dioxus_lib::prelude::*;
+use std::rc::Rc;
+#[cfg(feature = "web")]
+use wasm_bindgen::JsCast;
+
+// Reading native constraint validity and driving `requestSubmit()` both need
+// real DOM access, which only the `web` renderer's `MountedData` provides via
+// `as_web_event()`. Other renderers (desktop, SSR, liveview) get a no-op so
+// the crate still compiles for them; `Switch` simply can't observe native
+// validity or auto-submit on Enter there.
+#[cfg(feature = "web")]
+fn native_validity(element: &Rc<MountedData>) -> Option<bool> {
+    element
+        .as_web_event()
+        .dyn_ref::<web_sys::Element>()
+        .and_then(|el| el.dyn_ref::<web_sys::HtmlInputElement>().cloned())
+        .map(|input| input.validity().valid())
+}
+
+#[cfg(not(feature = "web"))]
+fn native_validity(_element: &Rc<MountedData>) -> Option<bool> {
+    None
+}
+
+#[cfg(feature = "web")]
+fn submit_enclosing_form(element: &Rc<MountedData>) {
+    let element = element.clone();
+    spawn(async move {
+        let _ = element
+            .as_web_event()
+            .dyn_ref::<web_sys::Element>()
+            .and_then(|el| el.closest("form").ok().flatten())
+            .and_then(|form| form.dyn_into::<web_sys::HtmlFormElement>().ok())
+            .map(|form| form.request_submit());
+    });
+}
+
+#[cfg(not(feature = "web"))]
+fn submit_enclosing_form(_element: &Rc<MountedData>) {}
+
+#[derive(Clone, Copy)]
+struct SwitchCtx {
+    checked: Signal<bool>,
+    label_id: Signal<Option<String>>,
+    description_id: Signal<Option<String>>,
+}
 
 #[derive(Props, Clone, PartialEq)]
 pub struct SwitchProps {
@@ -62,6 +121,9 @@ pub struct SwitchProps {
     #[props(default = ReadOnlySignal::new(Signal::new(false)))]
     disabled: ReadOnlySignal<bool>,
 
+    #[props(default = ReadOnlySignal::new(Signal::new(false)))]
+    read_only: ReadOnlySignal<bool>,
+
     #[props(default)]
     required: ReadOnlySignal<bool>,
 
@@ -71,9 +133,15 @@ pub struct SwitchProps {
     #[props(default = ReadOnlySignal::new(Signal::new(String::from("on"))))]
     value: ReadOnlySignal<String>,
 
+    #[props(default = ReadOnlySignal::new(Signal::new(false)))]
+    invalid: ReadOnlySignal<bool>,
+
     #[props(default)]
     on_checked_change: Callback<bool>,
 
+    #[props(default)]
+    on_validity_change: Callback<bool>,
+
     #[props(extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 
@@ -88,6 +156,49 @@ pub fn Switch(props: SwitchProps) -> Element {
         props.on_checked_change,
     );
 
+    let mut mounted: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut input_mounted: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut natively_valid: Signal<bool> = use_signal(|| true);
+
+    // Re-reads the hidden input's native constraint validity (required, etc.)
+    // and reports a transition through `on_validity_change`. Driven by real
+    // DOM validity rather than any one constraint so it keeps working if more
+    // constraints (pattern, min, ...) are added later.
+    let mut recheck_validity = move || {
+        let Some(element) = input_mounted() else {
+            return;
+        };
+        let Some(is_valid) = native_validity(&element) else {
+            return;
+        };
+        if is_valid != natively_valid() {
+            natively_valid.set(is_valid);
+            props.on_validity_change.call(is_valid);
+        }
+    };
+
+    // Setting the hidden checkbox's `checked` or `required` from code fires no
+    // DOM event, so re-check after every render that changes either.
+    use_effect(move || {
+        let _ = checked();
+        let _ = (props.required)();
+        recheck_validity();
+    });
+
+    let ctx = use_context_provider(|| SwitchCtx {
+        checked,
+        label_id: Signal::new(None),
+        description_id: Signal::new(None),
+    });
+
+    let toggle = move || {
+        if (props.disabled)() || (props.read_only)() {
+            return;
+        }
+        let new_checked = !checked();
+        set_checked.call(new_checked);
+    };
+
     rsx! {
         button {
             r#type: "button",
@@ -95,20 +206,39 @@ pub fn Switch(props: SwitchProps) -> Element {
             value: props.value,
             aria_checked: checked,
             aria_required: props.required,
+            aria_readonly: props.read_only,
+            // `Option<String>` so the attribute is omitted entirely when no
+            // `SwitchLabel`/description is present, rather than emitting an
+            // empty `aria-labelledby=""`.
+            aria_labelledby: (ctx.label_id)(),
+            aria_describedby: (ctx.description_id)(),
             disabled: props.disabled,
             "data-state": if checked() { "checked" } else { "unchecked" },
             // Only add data-disabled when actually disabled
             "data-disabled": if (props.disabled)() { "true" } else { "false" },
+            "data-readonly": if (props.read_only)() { "true" } else { "false" },
+            "data-invalid": if (props.invalid)() || !natively_valid() { "true" } else { "false" },
 
-            onclick: move |_| {
-                let new_checked = !checked();
-                set_checked.call(new_checked);
-            },
+            onmounted: move |e| mounted.set(Some(e.data())),
+
+            // The only place `checked` is toggled: a native `<button>` already
+            // fires `click` for a pointer click, a Space key up, and (unless
+            // suppressed below) an Enter key down - toggling here too would
+            // double-toggle on keyboard activation.
+            onclick: move |_| toggle(),
 
-            // Switches should only toggle on Space, not Enter
+            // Enter submits the enclosing form (native button semantics) instead of
+            // being swallowed; Space is left alone and toggles via the native
+            // click triggered on key up, handled by `onclick` above.
             onkeydown: move |e| {
                 if e.key() == Key::Enter {
+                    // A native button fires `click` on Enter key down, which would
+                    // toggle `checked` in addition to submitting the form. Suppress
+                    // that and drive submission explicitly instead.
                     e.prevent_default();
+                    if let Some(element) = mounted() {
+                        submit_enclosing_form(&element);
+                    }
                 }
             },
 
@@ -125,6 +255,21 @@ pub fn Switch(props: SwitchProps) -> Element {
             value: props.value,
             checked,
             disabled: props.disabled,
+            required: props.required,
+            // Seed `natively_valid` as soon as the element exists so `data-invalid`
+            // reflects reality (e.g. a `required`, unchecked switch) immediately,
+            // rather than waiting for a first `invalid`/`input` event.
+            onmounted: move |e| {
+                input_mounted.set(Some(e.data()));
+                recheck_validity();
+            },
+            // Fires when the browser rejects a form submission because this
+            // control fails constraint validation (e.g. `required` and unchecked).
+            oninvalid: move |_| recheck_validity(),
+            // Setting `checked` from code fires no `input` event (the effect
+            // above re-checks those changes); this only catches a direct
+            // change to the checkbox itself.
+            oninput: move |_| recheck_validity(),
             style: "transform: translateX(-100%); position: absolute; pointer-events: none; opacity: 0; margin: 0; width: 0; height: 0;",
         }
     }
@@ -142,3 +287,70 @@ pub fn SwitchThumb(props: SwitchThumbProps) -> Element {
         span { ..props.attributes }
     }
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SwitchLabelProps {
+    #[props(default = ReadOnlySignal::new(Signal::new(String::from("On"))))]
+    on_label: ReadOnlySignal<String>,
+
+    #[props(default = ReadOnlySignal::new(Signal::new(String::from("Off"))))]
+    off_label: ReadOnlySignal<String>,
+
+    /// Optional description text, wired to the switch's `aria-describedby`.
+    #[props(default)]
+    description: ReadOnlySignal<String>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A label to pair with [`Switch`]. Shows `on_label`/`off_label` depending on
+/// the switch's checked state and wires itself to the switch's
+/// `aria-labelledby` (and `aria-describedby`, if `description` is set).
+///
+/// Must be rendered inside a [`Switch`]'s children.
+#[component]
+pub fn SwitchLabel(props: SwitchLabelProps) -> Element {
+    let mut ctx: SwitchCtx = use_context();
+
+    // Derived from this component's scope id rather than a process-global
+    // counter, so the id is stable across server and client renders instead
+    // of depending on render order.
+    let scope_id = current_scope_id().expect("SwitchLabel must be rendered inside a component");
+    let label_id = format!("switch-label-{}", scope_id.0);
+    let description_id = format!("switch-description-{}", scope_id.0);
+
+    // Set directly during render, not inside `use_effect`: effects only run
+    // after a client-side commit, so on a single-pass server render the
+    // switch button would come out with no `aria-labelledby`/`aria-describedby`
+    // at all. A plain assignment here runs unconditionally, on every render.
+    ctx.label_id.set(Some(label_id.clone()));
+    let has_description = !(props.description)().is_empty();
+    ctx.description_id
+        .set(has_description.then(|| description_id.clone()));
+
+    let checked = ctx.checked;
+
+    rsx! {
+        span {
+            id: label_id,
+            "data-state": if checked() { "checked" } else { "unchecked" },
+            ..props.attributes,
+
+            span {
+                "data-state": "checked",
+                style: if checked() { "" } else { "display: none;" },
+                {props.on_label}
+            }
+            span {
+                "data-state": "unchecked",
+                style: if checked() { "display: none;" } else { "" },
+                {props.off_label}
+            }
+        }
+
+        if !(props.description)().is_empty() {
+            span { id: description_id, {props.description} }
+        }
+    }
+}
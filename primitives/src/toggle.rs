@@ -0,0 +1,107 @@
+//! A two-state toggle button.
+//!
+//! Unlike [`Switch`](crate::switch::Switch), which models an on/off `switch`
+//! role, `Toggle` models a pressed/not-pressed button (e.g. a bold/italic
+//! formatting control) via `aria-pressed`, so assistive tech announces
+//! "pressed"/"not pressed" instead of "checked"/"unchecked".
+//!
+//! #### Features
+//! - Supports ARIA keyboard interactions.
+//! - Can be controlled or uncontrolled.
+//!
+//! ## Example
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_primitives::toggle::Toggle;
+//!
+//! #[component]
+//! fn MyComponent() -> Element {
+//!     rsx! {
+//!         Toggle {
+//!             class: "toggle",
+//!             default_pressed: false,
+//!             on_pressed_change: move |new_pressed| println!("pressed: {new_pressed}"),
+//!             "B"
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Props
+//!
+//! | Prop                  | Description                                               | Default   |
+//! | --------------------- | --------------------------------------------------------- | --------- |
+//! | `pressed`             | The controlled pressed value.                             | `None`    |
+//! | `default_pressed`     | The default pressed state.                                | `false`   |
+//! | `disabled`            | Whether the toggle is disabled.                           | `false`   |
+//! | `on_pressed_change`   | Callback for state changes. Required with `pressed` prop. | `None`    |
+//!
+//! ### Attributes
+//!
+//! | Attribute         | States                    |
+//! | ----------------- | ------------------------- |
+//! | `data-state`      | `on` or `off`             |
+//! | `data-disabled`   | `true` or `false`         |
+//!
+//! ## Accessibility
+//!
+//! Exposes `aria-pressed` rather than `role="switch"`/`aria-checked`, per the
+//! ARIA [toggle button pattern](https://www.w3.org/WAI/ARIA/apg/patterns/button/).
+//!
+//! **Keyboard Interactions**
+//! | Key   | Description           |
+//! | ----- | --------------------- |
+//! | Space | Activate the toggle.  |
+//! | Enter | Activate the toggle.  |
+use crate::use_controlled;
+use dioxus_lib::prelude::*;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToggleProps {
+    pressed: Option<Signal<bool>>,
+
+    #[props(default = false)]
+    default_pressed: bool,
+
+    #[props(default = ReadOnlySignal::new(Signal::new(false)))]
+    disabled: ReadOnlySignal<bool>,
+
+    #[props(default)]
+    on_pressed_change: Callback<bool>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+
+    children: Element,
+}
+
+#[component]
+pub fn Toggle(props: ToggleProps) -> Element {
+    let (pressed, set_pressed) = use_controlled(
+        props.pressed,
+        props.default_pressed,
+        props.on_pressed_change,
+    );
+
+    rsx! {
+        button {
+            r#type: "button",
+            aria_pressed: pressed,
+            disabled: props.disabled,
+            "data-state": if pressed() { "on" } else { "off" },
+            // Always present: "true" when disabled, "false" otherwise
+            "data-disabled": if (props.disabled)() { "true" } else { "false" },
+
+            // A real `button` element already activates on both Space (key up)
+            // and Enter (key down) natively, so `onclick` alone is enough -
+            // unlike `Switch`, there's no default behavior to suppress here.
+            onclick: move |_| {
+                let new_pressed = !pressed();
+                set_pressed.call(new_pressed);
+            },
+
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}